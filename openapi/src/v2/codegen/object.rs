@@ -21,6 +21,14 @@ pub struct ApiObject {
     pub fields: Vec<ObjectField>,
     /// Paths with operations which address this object.
     pub paths: BTreeMap<String, PathOps>,
+    /// Whether the schema for this object is marked `deprecated`.
+    pub deprecated: bool,
+    /// Optional note explaining the deprecation.
+    pub deprecation_note: Option<String>,
+    /// Whether builders for this object should be emitted in borrowed
+    /// (zero-copy) mode, parameterizing the builder over a lifetime `'a` and
+    /// taking borrowed parameters instead of owned values.
+    pub borrowed: bool,
 }
 
 /// Operations in a path.
@@ -44,6 +52,10 @@ pub struct OpRequirement {
     pub params: Vec<Parameter>,
     /// Whether the object itself is required (in body) for this operation.
     pub body_required: bool,
+    /// Whether this operation is marked `deprecated` in the schema.
+    pub deprecated: bool,
+    /// Optional note explaining the deprecation.
+    pub deprecation_note: Option<String>,
 }
 
 /// Represents some parameter somewhere (header, path, query, etc.).
@@ -55,6 +67,74 @@ pub struct Parameter {
     pub ty_path: String,
     /// Whether this parameter is required.
     pub required: bool,
+    /// Rendered Rust expression for the parameter's schema `default`, if any.
+    /// When set, the builder seeds the matching optional param with this value
+    /// instead of `None`.
+    pub default: Option<String>,
+    /// Validation constraints declared for this parameter, if any.
+    pub constraints: Constraints,
+}
+
+/// Validation constraints captured from an OpenAPI schema.
+///
+/// These are enforced client-side by the builder's generated `validate` method
+/// before a request is sent, so malformed payloads are rejected without a
+/// round-trip to the server.
+#[derive(Debug, Clone, Default)]
+pub struct Constraints {
+    /// Minimum length of a string (`minLength`).
+    pub min_length: Option<usize>,
+    /// Maximum length of a string (`maxLength`).
+    pub max_length: Option<usize>,
+    /// Inclusive lower bound for a number (`minimum`).
+    pub minimum: Option<f64>,
+    /// Inclusive upper bound for a number (`maximum`).
+    pub maximum: Option<f64>,
+    /// Exclusive lower bound for a number (`exclusiveMinimum`).
+    pub exclusive_minimum: Option<f64>,
+    /// Exclusive upper bound for a number (`exclusiveMaximum`).
+    pub exclusive_maximum: Option<f64>,
+    /// Regular expression the value must match (`pattern`).
+    pub pattern: Option<String>,
+    /// Minimum number of items in a collection (`minItems`).
+    pub min_items: Option<usize>,
+    /// Maximum number of items in a collection (`maxItems`).
+    pub max_items: Option<usize>,
+    /// Allowed values for the field (`enum`), rendered as Rust expressions.
+    pub enum_values: Option<Vec<String>>,
+}
+
+impl Constraints {
+    /// Returns whether no constraint is set (i.e. there is no check to emit).
+    pub fn is_empty(&self) -> bool {
+        self.min_length.is_none()
+            && self.max_length.is_none()
+            && self.minimum.is_none()
+            && self.maximum.is_none()
+            && self.exclusive_minimum.is_none()
+            && self.exclusive_maximum.is_none()
+            && self.pattern.is_none()
+            && self.min_items.is_none()
+            && self.max_items.is_none()
+            && self.enum_values.is_none()
+    }
+}
+
+/// Kind of collection backing a field (derived from the OpenAPI schema type).
+///
+/// This lets the builder emit incremental setters (`add_*`/`insert_*`) that
+/// grow the inner container one element at a time instead of forcing the
+/// caller to construct the whole `Vec`/set/map up front.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CollectionKind {
+    /// Not a collection (scalar or nested object).
+    None,
+    /// A `Vec<T>`.
+    Vec,
+    /// A set type (emitted with `Default` + `insert`).
+    Set,
+    /// A map type (emitted with `insert(key, value)`).
+    Map,
 }
 
 /// Represents a struct field.
@@ -70,6 +150,43 @@ pub struct ObjectField {
     pub is_required: bool,
     /// Whether this field should be boxed.
     pub boxed: bool,
+    /// Kind of collection this field represents (if any), so that the builder
+    /// can emit element-wise setters alongside the full-value setter.
+    pub collection: CollectionKind,
+    /// Rendered Rust expression for the schema's `default`, if any. When set,
+    /// the object gets a hand-written `impl Default` seeding this field (and
+    /// the builder seeds the matching optional param) with this value instead
+    /// of `None`/`Default::default()`.
+    pub default: Option<String>,
+    /// Whether this field's schema is marked `deprecated`.
+    pub deprecated: bool,
+    /// Optional note explaining the deprecation.
+    pub deprecation_note: Option<String>,
+    /// Describes the generated object type backing this field, if the field
+    /// is itself an object. This enables an alternate `with_<field>(|b| ...)`
+    /// setter that builds the nested object through its own builder.
+    pub nested_object: Option<NestedObject>,
+    /// Validation constraints declared for this field, if any.
+    pub constraints: Constraints,
+}
+
+/// Describes a nested object field's own builder, so the parent builder can
+/// thread the nested builder's required-field type-state generics through the
+/// `with_<field>(|b| ...)` closure bound instead of naming the bare type
+/// (which only compiles when the nested type has no required fields).
+#[derive(Debug, Clone)]
+pub struct NestedObject {
+    /// Name of the nested object's generated type.
+    pub name: String,
+    /// Snake-cased names of the nested object's own required fields, in the
+    /// same order its builder's generics are declared.
+    pub required_fields: Vec<String>,
+}
+
+impl Default for CollectionKind {
+    fn default() -> Self {
+        CollectionKind::None
+    }
 }
 
 impl ApiObject {
@@ -84,6 +201,9 @@ impl ApiObject {
             name: name.into(),
             fields: vec![],
             paths: BTreeMap::new(),
+            deprecated: false,
+            deprecation_note: None,
+            borrowed: false,
         }
     }
 
@@ -113,6 +233,9 @@ impl ApiObject {
                 method: None,
                 op_id: None,
                 body_required: true,
+                deprecated: self.deprecated,
+                deprecation_note: self.deprecation_note.as_deref(),
+                borrowed: self.borrowed,
                 fields: &self.fields,
                 global_params: &[],
                 local_params: &[],
@@ -134,6 +257,9 @@ impl ApiObject {
                             op_id: req.id.as_ref().map(String::as_str),
                             method: Some(method),
                             body_required: req.body_required,
+                            deprecated: req.deprecated,
+                            deprecation_note: req.deprecation_note.as_deref(),
+                            borrowed: self.borrowed,
                             fields: &self.fields,
                             global_params: &path_ops.params,
                             local_params: &req.params,
@@ -157,7 +283,11 @@ impl<'a> ApiObjectImpl<'a> {
         let has_multiple = self.builders.len() > 1;
 
         for builder in &self.builders {
-            f.write_str("\n    #[inline]\n    pub fn ")?;
+            f.write_str("\n")?;
+            if builder.deprecated {
+                write_deprecated_attr(builder.deprecation_note, "    ", f)?;
+            }
+            f.write_str("    #[inline]\n    pub fn ")?;
             match (builder.op_id, builder.method) {
                 // If there's a method and we don't have any collisions
                 // (i.e., two or more paths for same object), then we default
@@ -184,17 +314,61 @@ impl<'a> ApiObjectImpl<'a> {
                 _ => f.write_str("builder")?,
             }
 
+            // `'a` is a generic on the constructor method itself (not on the
+            // surrounding `impl`, whose `Self` type never mentions it —
+            // binding it at the impl level is E0207, "lifetime parameter not
+            // constrained by the self type").
+            if builder.uses_lifetime() {
+                f.write_str("<'a>")?;
+            }
             f.write_str("() -> ")?;
             builder.write_name(f)?;
             builder.write_generics_if_necessary(f, true)?;
             f.write_str(" {\n        ")?;
+
+            let needs_container = builder.needs_container();
+
+            // Optional params with a declared default must be seeded with it
+            // rather than `None`. When the params live in a container (the
+            // common case), `inner: Default::default()` would zero them out, so
+            // we build the container explicitly and seed it before the literal.
+            let container_defaults: Vec<(String, &str)> = if needs_container {
+                builder
+                    .struct_fields_iter()
+                    .filter(|(_, _, prop)| prop.is_parameter() && !prop.is_required())
+                    .filter_map(|(name, _, _)| {
+                        builder
+                            .param_default(name)
+                            .map(|expr| (name.to_snek_case(), expr))
+                    })
+                    .collect()
+            } else {
+                vec![]
+            };
+
+            if !container_defaults.is_empty() {
+                f.write_str("let mut inner: ")?;
+                builder.write_container_name(f)?;
+                if builder.uses_lifetime() {
+                    f.write_str("<'a>")?;
+                }
+                f.write_str(" = Default::default();")?;
+                for (sk, expr) in &container_defaults {
+                    write!(f, "\n        inner.param_{} = Some({});", sk, expr)?;
+                }
+                f.write_str("\n        ")?;
+            }
+
             builder.write_name(f)?;
             f.write_str(" {")?;
 
-            let needs_container = builder.needs_container();
             if needs_container {
                 f.write_str("\n            ")?;
-                f.write_str("inner: Default::default(),")?;
+                if container_defaults.is_empty() {
+                    f.write_str("inner: Default::default(),")?;
+                } else {
+                    f.write_str("inner,")?;
+                }
             } else if builder.body_required {
                 f.write_str("\n            ")?;
                 f.write_str("body: Default::default(),")?;
@@ -215,7 +389,11 @@ impl<'a> ApiObjectImpl<'a> {
                     } else if prop.is_parameter() && !needs_container {
                         f.write_str("\n            param_")?;
                         f.write_str(&name.to_snek_case())?;
-                        f.write_str(": None,")?;
+                        // Seed with the schema default (if declared) instead of `None`.
+                        match builder.param_default(name) {
+                            Some(expr) => write!(f, ": Some({}),", expr)?,
+                            None => f.write_str(": None,")?,
+                        }
                     }
 
                     Ok(())
@@ -237,6 +415,9 @@ pub struct ApiObjectBuilder<'a> {
     method: Option<HttpMethod>,
     object: &'a str,
     body_required: bool,
+    deprecated: bool,
+    deprecation_note: Option<&'a str>,
+    borrowed: bool,
     fields: &'a [ObjectField],
     global_params: &'a [Parameter],
     local_params: &'a [Parameter],
@@ -305,6 +486,32 @@ impl<'a> ApiObjectBuilder<'a> {
             .filter_map(|p| p)
     }
 
+    /// Returns the rendered default expression for the parameter with this
+    /// name, if one is declared. Used to seed optional params in the builder
+    /// constructor with the schema default rather than `None`. Local params
+    /// override global ones, matching `struct_fields_iter`.
+    fn param_default(&self, name: &str) -> Option<&'a str> {
+        self.local_params
+            .iter()
+            .chain(self.global_params.iter())
+            .find(|p| p.name == name)
+            .and_then(|p| p.default.as_deref())
+    }
+
+    /// Returns whether the generated builder actually binds the `'a` lifetime.
+    ///
+    /// Borrowed mode only introduces `'a` to hold borrowed *parameters*; a
+    /// borrowed builder with no parameters (e.g. only required body fields)
+    /// never mentions `'a`, so emitting `<'a, ...>` would leave it unused
+    /// (E0392). We therefore parameterize over `'a` only when there's at least
+    /// one parameter to borrow.
+    fn uses_lifetime(&self) -> bool {
+        self.borrowed
+            && self
+                .struct_fields_iter()
+                .any(|(_, _, prop)| prop.is_parameter())
+    }
+
     /// Returns whether a separate container is needed for the builder struct.
     fn needs_container(&self) -> bool {
         self.local_params
@@ -355,17 +562,24 @@ impl<'a> ApiObjectBuilder<'a> {
     where
         F: Write,
     {
+        // In borrowed mode the builder is parameterized over `'a` whenever it
+        // actually borrows a parameter, even if it has no required (type-state)
+        // fields.
         let mut is_generic = false;
+        if self.uses_lifetime() {
+            f.write_str("<'a")?;
+            is_generic = true;
+        }
+
         // Inspect fields and parameters and write generics.
         self.struct_fields_iter()
             .filter(|(_, _, prop)| prop.is_required())
-            .enumerate()
-            .try_for_each(|(i, (name, _, _))| {
-                if i == 0 {
+            .try_for_each(|(name, _, _)| {
+                if is_generic {
+                    f.write_str(", ")?;
+                } else {
                     is_generic = true;
                     f.write_str("<")?;
-                } else {
-                    f.write_str(", ")?;
                 }
 
                 if types {
@@ -412,12 +626,629 @@ impl<'a> ApiObjectBuilder<'a> {
             f.write_str("\n    param_")?;
             f.write_str(&name)?;
             f.write_str(": Option<")?;
-            f.write_str(&ty)?;
+            // In borrowed mode parameters hold a borrow so callers can build
+            // requests straight from borrowed configuration without cloning.
+            if self.borrowed {
+                f.write_str(&borrowed_param_ty(ty))?;
+            } else {
+                f.write_str(&ty)?;
+            }
             f.write_str(">,")?;
         }
 
         Ok(())
     }
+
+    /// Writes this builder's name with the required field `filled` flipped from
+    /// its `Missing<Field>` marker to the concrete "present" marker, leaving
+    /// the other fields' generic parameters untouched (they stay whatever
+    /// state the caller's impl is already generic over). Used as the return
+    /// type of a setter which fills a single required field/parameter.
+    fn write_generics_filled<F>(&self, filled: &str, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let mut is_generic = false;
+        if self.uses_lifetime() {
+            f.write_str("<'a")?;
+            is_generic = true;
+        }
+
+        self.struct_fields_iter()
+            .filter(|(_, _, prop)| prop.is_required())
+            .try_for_each(|(name, _, _)| {
+                if is_generic {
+                    f.write_str(", ")?;
+                } else {
+                    is_generic = true;
+                    f.write_str("<")?;
+                }
+
+                let cc = name.to_camel_case();
+                if cc == filled {
+                    // The field being set: a concrete "present" marker, not
+                    // the bare generic parameter name (which would just keep
+                    // this field generic over whatever it already was).
+                    f.write_str(self.helper_module_prefix)?;
+                    f.write_str(&cc)
+                } else {
+                    // Every other field: thread the impl's own generic
+                    // parameter through unchanged, so its current state
+                    // (filled or not) is preserved.
+                    f.write_str(&cc)
+                }
+            })?;
+
+        if is_generic {
+            f.write_str(">")?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes this builder's name with every required field's marker at its
+    /// concrete "present" state.
+    ///
+    /// Used to scope `build()`/`validate()` to the one instantiation of the
+    /// builder where the type-state markers actually guarantee every
+    /// required field has been set; emitting them into the fully-generic
+    /// setter `impl<Foo, Bar> {Name}Builder<Foo, Bar>` block instead would let
+    /// `build()` be called while `Foo`/`Bar` are still `Missing<..>`.
+    fn write_generics_all_present<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let mut is_generic = false;
+        if self.uses_lifetime() {
+            f.write_str("<'a")?;
+            is_generic = true;
+        }
+
+        self.struct_fields_iter()
+            .filter(|(_, _, prop)| prop.is_required())
+            .try_for_each(|(name, _, _)| {
+                if is_generic {
+                    f.write_str(", ")?;
+                } else {
+                    is_generic = true;
+                    f.write_str("<")?;
+                }
+
+                f.write_str(self.helper_module_prefix)?;
+                f.write_str(&name.to_camel_case())
+            })?;
+
+        if is_generic {
+            f.write_str(">")?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the full-value setter for a single field or parameter: it takes
+    /// the whole value, stores it (in `param_<field>` for parameters, in
+    /// `body.<field>` for body fields) and returns either `Self` (optional) or
+    /// the builder with this field's `Missing<Field>` marker flipped to filled
+    /// (required; sound because the builder is `#[repr(transparent)]`).
+    fn write_full_setter<F>(
+        &self,
+        prop: Property,
+        name: &str,
+        ty: &str,
+        f: &mut F,
+    ) -> fmt::Result
+    where
+        F: Write,
+    {
+        // Optional body fields can only be set when the body is part of this
+        // builder; otherwise there's nowhere to store them.
+        if prop.is_field() && !self.body_required {
+            return Ok(());
+        }
+
+        let sk = name.to_snek_case();
+        let prefix = if self.needs_container() {
+            "self.inner."
+        } else {
+            "self."
+        };
+
+        f.write_str("\n    #[inline]\n    pub fn ")?;
+        f.write_str(&sk)?;
+        f.write_str("(mut self, value: ")?;
+        if prop.is_parameter() && self.borrowed {
+            f.write_str(&borrowed_param_ty(ty))?;
+        } else {
+            f.write_str(&ty)?;
+        }
+        f.write_str(") -> ")?;
+        self.write_setter_return(prop, &sk, f)?;
+        f.write_str(" {\n        ")?;
+        if prop.is_parameter() {
+            write!(f, "{}param_{} = Some(value);", prefix, sk)?;
+        } else if prop.is_required() {
+            write!(f, "{}body.{} = value;", prefix, sk)?;
+        } else {
+            write!(f, "{}body.{} = Some(value);", prefix, sk)?;
+        }
+
+        if prop.is_required() {
+            f.write_str("\n        unsafe { ::std::mem::transmute(self) }\n    }")
+        } else {
+            f.write_str("\n        self\n    }")
+        }
+    }
+
+    /// Writes element-wise setters for a collection field alongside the
+    /// existing full-value setter: `add_<field>(item)` for `Vec`/set fields
+    /// and `insert_<field>(key, value)` for map fields.
+    ///
+    /// The inner container is initialized on first call and mutated in place.
+    /// Like the full-value setters, these return `self`; for a required
+    /// collection the returned type flips the field's `Missing<Field>` marker
+    /// to filled (the builder is `#[repr(transparent)]`, so the transmute is
+    /// sound).
+    pub(super) fn write_collection_setters<F>(
+        &self,
+        kind: CollectionKind,
+        prop: Property,
+        name: &str,
+        ty: &str,
+        f: &mut F,
+    ) -> fmt::Result
+    where
+        F: Write,
+    {
+        // Optional/required body fields can only be set when the body is part
+        // of this builder; otherwise there's nowhere to store them (mirrors
+        // the guard in `write_full_setter`).
+        if prop.is_field() && !self.body_required {
+            return Ok(());
+        }
+
+        let sk = name.to_snek_case();
+        let prefix = if self.needs_container() {
+            "self.inner."
+        } else {
+            "self."
+        };
+
+        // Parameters and optional body fields are stored behind an `Option`
+        // (`param_<field>` / `body.<field>`), so the container is lazily
+        // created on first insertion. A *required* collection body field is
+        // stored inline as `body.<field>` (a bare `Vec`/set/map, no `Option`
+        // and no `param_` prefix), so it's mutated directly.
+        let (target, optionful) = if prop.is_parameter() {
+            (format!("{}param_{}", prefix, sk), true)
+        } else {
+            (format!("{}body.{}", prefix, sk), !prop.is_required())
+        };
+
+        match kind {
+            CollectionKind::None => return Ok(()),
+            CollectionKind::Vec | CollectionKind::Set => {
+                let (init, push) = if let CollectionKind::Set = kind {
+                    ("Default::default", "insert")
+                } else {
+                    ("Vec::new", "push")
+                };
+                f.write_str("\n    #[inline]\n    pub fn add_")?;
+                f.write_str(&sk)?;
+                f.write_str("(mut self, value: ")?;
+                f.write_str(inner_type(ty))?;
+                f.write_str(") -> ")?;
+                self.write_setter_return(prop, &sk, f)?;
+                f.write_str(" {\n        ")?;
+                if optionful {
+                    write!(f, "{}.get_or_insert_with({}).{}(value);", target, init, push)?;
+                } else {
+                    write!(f, "{}.{}(value);", target, push)?;
+                }
+            }
+            CollectionKind::Map => {
+                let (key_ty, val_ty) = map_types(ty);
+                f.write_str("\n    #[inline]\n    pub fn insert_")?;
+                f.write_str(&sk)?;
+                f.write_str("(mut self, key: ")?;
+                f.write_str(key_ty)?;
+                f.write_str(", value: ")?;
+                f.write_str(val_ty)?;
+                f.write_str(") -> ")?;
+                self.write_setter_return(prop, &sk, f)?;
+                f.write_str(" {\n        ")?;
+                if optionful {
+                    write!(f, "{}.get_or_insert_with(Default::default).insert(key, value);", target)?;
+                } else {
+                    write!(f, "{}.insert(key, value);", target)?;
+                }
+            }
+        }
+
+        if prop.is_required() {
+            f.write_str("\n        unsafe { ::std::mem::transmute(self) }\n    }")
+        } else {
+            f.write_str("\n        self\n    }")
+        }
+    }
+
+    /// Writes the return type of a setter that fills the given field: the
+    /// builder with that field's marker flipped for a required field, or
+    /// `Self` for an optional one.
+    fn write_setter_return<F>(&self, prop: Property, sk: &str, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        if prop.is_required() {
+            self.write_name(f)?;
+            self.write_generics_filled(&sk.to_camel_case(), f)
+        } else {
+            f.write_str("Self")
+        }
+    }
+
+    /// Writes the nested-builder setter `with_<field>(|b| ...)` for a required
+    /// object-typed field.
+    ///
+    /// The closure receives the nested type's builder in its initial
+    /// (all-required-fields-`Missing`) state and must return it in its final
+    /// (all-required-fields-filled) state, mirroring the generics
+    /// `write_generics_if_necessary`/`write_generics_filled` produce for the
+    /// nested type's own setters; a bare `{Nested}Builder` only names a real
+    /// type when the nested type has no required fields of its own, and a
+    /// closure that actually fills them returns a different, type-state
+    /// transitioned type, so the bound must thread those generics through
+    /// rather than naming the bare builder. A sub-field left unset is a
+    /// compile error (the closure's return type literally can't name it, so
+    /// there's no runtime "not set" case to report); `.build()` is only ever
+    /// callable on that all-filled instantiation (see `write_builder_impl`),
+    /// so its `Err` here can only be the nested object's own constraint
+    /// violations, which get wrapped in a `SubfieldBuildError` carrying the
+    /// static field name so the caller sees which sub-field's *value* was
+    /// rejected. On success the built value is stored and the field's
+    /// `Missing<Field>` marker flips to filled.
+    pub(super) fn write_nested_setter<F>(
+        &self,
+        nested: &NestedObject,
+        prop: Property,
+        name: &str,
+        f: &mut F,
+    ) -> fmt::Result
+    where
+        F: Write,
+    {
+        // Only required object-typed body fields get a nested builder.
+        if !prop.is_required() || prop.is_parameter() {
+            return Ok(());
+        }
+
+        let sk = name.to_snek_case();
+        let prefix = if self.needs_container() {
+            "self.inner."
+        } else {
+            "self."
+        };
+        let p = self.helper_module_prefix;
+
+        // The nested builder's starting (all-`Missing`) and finished
+        // (all-filled) generic argument lists.
+        let (mut missing_generics, mut filled_generics) = (String::new(), String::new());
+        if !nested.required_fields.is_empty() {
+            missing_generics.push('<');
+            filled_generics.push('<');
+            for (i, field_name) in nested.required_fields.iter().enumerate() {
+                if i > 0 {
+                    missing_generics.push_str(", ");
+                    filled_generics.push_str(", ");
+                }
+
+                let cc = field_name.to_camel_case();
+                missing_generics.push_str(p);
+                missing_generics.push_str("Missing");
+                missing_generics.push_str(&cc);
+                filled_generics.push_str(p);
+                filled_generics.push_str(&cc);
+            }
+            missing_generics.push('>');
+            filled_generics.push('>');
+        }
+
+        f.write_str("\n    #[inline]\n    pub fn with_")?;
+        f.write_str(&sk)?;
+        f.write_str("<F>(mut self, f: F) -> Result<")?;
+        self.write_name(f)?;
+        self.write_generics_filled(&sk.to_camel_case(), f)?;
+        write!(
+            f,
+            ", {}SubfieldBuildError>\n    where\n        F: FnOnce({}Builder{}) -> {}Builder{},\n    {{\n        ",
+            p, nested.name, missing_generics, nested.name, filled_generics
+        )?;
+        write!(
+            f,
+            "let built = f({}::builder())\n            .build()\n            .map_err(|e| {}SubfieldBuildError::new(\"{}\", e))?;\n        ",
+            nested.name, p, sk
+        )?;
+        write!(f, "{}body.{} = built;\n        ", prefix, sk)?;
+        f.write_str("Ok(unsafe { ::std::mem::transmute(self) })\n    }")
+    }
+
+    /// Writes the `validate` method which enforces the captured OpenAPI
+    /// constraints before a request is sent, returning the first violation as
+    /// a `ValidationError` naming the offending field and the violated
+    /// constraint. The send path calls this and short-circuits on failure.
+    pub(super) fn write_validate_method<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let prefix = if self.needs_container() {
+            "self.inner."
+        } else {
+            "self."
+        };
+
+        write!(
+            f,
+            "\n    fn validate(&self) -> Result<(), {}ValidationError> {{",
+            self.helper_module_prefix
+        )?;
+
+        // Parameters each carry their own constraints (local overrides global).
+        let mut seen = HashSet::new();
+        for param in self.local_params.iter().chain(self.global_params.iter()) {
+            if !seen.insert(param.name.as_str()) || param.constraints.is_empty() {
+                continue;
+            }
+
+            let sk = param.name.to_snek_case();
+            write!(f, "\n        if let Some(value) = &{}param_{} {{", prefix, sk)?;
+            self.write_constraint_checks(&sk, &param.ty_path, &param.constraints, f)?;
+            f.write_str("\n        }")?;
+        }
+
+        // Object (body) fields only matter when the body is part of this builder.
+        if self.body_required {
+            for field in self.fields.iter().filter(|fd| !fd.constraints.is_empty()) {
+                if field.is_required {
+                    write!(
+                        f,
+                        "\n        {{\n            let value = &{}body.{};",
+                        prefix, field.name
+                    )?;
+                    self.write_constraint_checks(&field.name, &field.ty_path, &field.constraints, f)?;
+                    f.write_str("\n        }")?;
+                } else {
+                    write!(
+                        f,
+                        "\n        if let Some(value) = &{}body.{} {{",
+                        prefix, field.name
+                    )?;
+                    self.write_constraint_checks(&field.name, &field.ty_path, &field.constraints, f)?;
+                    f.write_str("\n        }")?;
+                }
+            }
+        }
+
+        f.write_str("\n        Ok(())\n    }")
+    }
+
+    /// Writes the individual constraint checks for a single field, assuming a
+    /// borrowed binding named `value` is in scope. Each failed check returns a
+    /// `ValidationError` naming `field` and the violated constraint. `ty` is
+    /// the field/parameter's Rust type path, needed to compare `enum` values
+    /// against `value` at a matching type.
+    fn write_constraint_checks<F>(
+        &self,
+        field: &str,
+        ty: &str,
+        c: &Constraints,
+        f: &mut F,
+    ) -> fmt::Result
+    where
+        F: Write,
+    {
+        let p = self.helper_module_prefix;
+        let i = "\n            ";
+
+        if let Some(n) = c.min_length {
+            write!(f, "{}if value.len() < {} {{ return Err({}ValidationError::min_length(\"{}\", {})); }}", i, n, p, field, n)?;
+        }
+        if let Some(n) = c.max_length {
+            write!(f, "{}if value.len() > {} {{ return Err({}ValidationError::max_length(\"{}\", {})); }}", i, n, p, field, n)?;
+        }
+        if let Some(n) = c.min_items {
+            write!(f, "{}if value.len() < {} {{ return Err({}ValidationError::min_items(\"{}\", {})); }}", i, n, p, field, n)?;
+        }
+        if let Some(n) = c.max_items {
+            write!(f, "{}if value.len() > {} {{ return Err({}ValidationError::max_items(\"{}\", {})); }}", i, n, p, field, n)?;
+        }
+        if let Some(m) = c.minimum {
+            write!(f, "{}if (*value as f64) < {:?} {{ return Err({}ValidationError::minimum(\"{}\", {:?})); }}", i, m, p, field, m)?;
+        }
+        if let Some(m) = c.maximum {
+            write!(f, "{}if (*value as f64) > {:?} {{ return Err({}ValidationError::maximum(\"{}\", {:?})); }}", i, m, p, field, m)?;
+        }
+        if let Some(m) = c.exclusive_minimum {
+            write!(f, "{}if (*value as f64) <= {:?} {{ return Err({}ValidationError::exclusive_minimum(\"{}\", {:?})); }}", i, m, p, field, m)?;
+        }
+        if let Some(m) = c.exclusive_maximum {
+            write!(f, "{}if (*value as f64) >= {:?} {{ return Err({}ValidationError::exclusive_maximum(\"{}\", {:?})); }}", i, m, p, field, m)?;
+        }
+        if let Some(pat) = c.pattern.as_ref() {
+            // `regex` is the only crate that can realistically implement
+            // `pattern` matching, so a generated crate with a `pattern`
+            // constraint must depend on it; `OnceLock` is std (1.70+), so
+            // this doesn't also pull in `once_cell`.
+            let re = format!("RE_{}", field.to_snek_case().to_uppercase());
+            write!(
+                f,
+                "{}static {}: ::std::sync::OnceLock<regex::Regex> = ::std::sync::OnceLock::new();",
+                i, re
+            )?;
+            write!(
+                f,
+                "{}if !{}.get_or_init(|| regex::Regex::new({:?}).unwrap()).is_match(value) {{ return Err({}ValidationError::pattern(\"{}\")); }}",
+                i, re, pat, p, field
+            )?;
+        }
+        if let Some(vals) = c.enum_values.as_ref() {
+            // `value` is `&String` for `String`-typed fields, but the
+            // rendered literals are `&str`; compare through `as_str()` there.
+            // For every other type the literals are rendered at the field's
+            // own type, so `value` (already a reference to it) compares
+            // directly.
+            if ty == "String" {
+                write!(
+                    f,
+                    "{}if ![{}].contains(&value.as_str()) {{ return Err({}ValidationError::enum_mismatch(\"{}\")); }}",
+                    i, vals.join(", "), p, field
+                )?;
+            } else {
+                write!(
+                    f,
+                    "{}if ![{}].contains(value) {{ return Err({}ValidationError::enum_mismatch(\"{}\")); }}",
+                    i, vals.join(", "), p, field
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the builder's inherent `impl` block with one setter per field and
+    /// parameter: the full-value setter, plus element-wise setters for
+    /// collection-typed fields.
+    pub(super) fn write_builder_impl<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        // Nothing to set on an empty builder.
+        if !self.has_atleast_one_field() && !self.body_required {
+            return Ok(());
+        }
+
+        f.write_str("\nimpl")?;
+        self.write_generics_if_necessary(f, false)?;
+        f.write_str(" ")?;
+        self.write_name(f)?;
+        self.write_generics_if_necessary(f, false)?;
+        f.write_str(" {")?;
+
+        let field_by_name = |name: &str| self.fields.iter().find(|fd| fd.name == name);
+        self.struct_fields_iter().try_for_each(|(name, ty, prop)| {
+            self.write_full_setter(prop, name, ty, f)?;
+            if let Some(field) = field_by_name(name) {
+                self.write_collection_setters(field.collection, prop, name, ty, f)?;
+                if let Some(nested) = field.nested_object.as_ref() {
+                    self.write_nested_setter(nested, prop, name, f)?;
+                }
+            }
+
+            Ok(())
+        })?;
+
+        f.write_str("\n}\n")?;
+
+        // `validate()`/`build()` get their own impl block scoped to the single
+        // instantiation where every required field's marker is concretely
+        // "present", rather than living in the fully-generic setter impl
+        // above. Emitting them there would make `build()` callable while a
+        // required field is still `Missing<..>` (e.g. `Foo::builder().build()`
+        // on a `Default`-constructed body with required fields never set),
+        // defeating the type-state guarantee the builder exists to provide.
+        // `validate()` is a no-op `Ok(())` body when nothing on this builder
+        // carries constraints, but `build()` itself must still exist
+        // unconditionally (at this one instantiation): a nested-object field
+        // (see `write_nested_setter`) calls `.build()` on *any* required
+        // sub-object's builder regardless of whether that sub-object happens
+        // to declare constraints.
+        f.write_str("\nimpl")?;
+        if self.uses_lifetime() {
+            f.write_str("<'a>")?;
+        }
+        f.write_str(" ")?;
+        self.write_name(f)?;
+        self.write_generics_all_present(f)?;
+        f.write_str(" {")?;
+
+        self.write_validate_method(f)?;
+
+        let prefix = if self.needs_container() {
+            "self.inner."
+        } else {
+            "self."
+        };
+        f.write_str("\n    #[inline]\n    pub fn build(self) -> Result<")?;
+        if self.body_required {
+            f.write_str(&self.object)?;
+        } else {
+            f.write_str("()")?;
+        }
+        write!(f, ", {}ValidationError> {{\n        self.validate()?;\n        Ok(", self.helper_module_prefix)?;
+        if self.body_required {
+            write!(f, "{}body", prefix)?;
+        } else {
+            f.write_str("()")?;
+        }
+        f.write_str(")\n    }")?;
+
+        f.write_str("\n}\n")
+    }
+}
+
+/// Returns the element type of a `Vec<T>`/set type path (the substring between
+/// the first `<` and the matching final `>`), falling back to the whole path.
+fn inner_type(ty: &str) -> &str {
+    match (ty.find('<'), ty.rfind('>')) {
+        (Some(start), Some(end)) if start < end => ty[start + 1..end].trim(),
+        _ => ty,
+    }
+}
+
+/// Splits a map type path (e.g. `BTreeMap<K, V>`) into its key and value types.
+fn map_types(ty: &str) -> (&str, &str) {
+    let inner = inner_type(ty);
+    // Split on the top-level comma (map generics never nest a comma in the key).
+    let mut depth = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => return (inner[..i].trim(), inner[i + 1..].trim()),
+            _ => {}
+        }
+    }
+
+    (inner, inner)
+}
+
+/// Maps an owned parameter type to its borrowed (`'a`-bound) equivalent for
+/// zero-copy builder mode: `String` becomes `&'a str`, `Vec<u8>` becomes
+/// `&'a [u8]`, and everything else is borrowed as `&'a <ty>`.
+fn borrowed_param_ty(ty: &str) -> String {
+    match ty {
+        "String" | "str" => "&'a str".to_string(),
+        "Vec<u8>" | "[u8]" => "&'a [u8]".to_string(),
+        _ => format!("&'a {}", ty),
+    }
+}
+
+/// Escapes backslashes and double quotes in a string so it can be
+/// interpolated into a Rust string literal (as `derive_builder` does for
+/// its own generated doc comments).
+fn escape_str_literal(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Writes a `#[deprecated]` attribute (with an optional `note`) at the given
+/// indentation, followed by a newline.
+fn write_deprecated_attr<F>(note: Option<&str>, indent: &str, f: &mut F) -> fmt::Result
+where
+    F: Write,
+{
+    f.write_str(indent)?;
+    match note {
+        Some(note) => writeln!(f, "#[deprecated(note = \"{}\")]", escape_str_literal(note)),
+        None => f.write_str("#[deprecated]\n"),
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -461,11 +1292,24 @@ impl<'a> Display for ApiObjectImpl<'a> {
             return Ok(());
         }
 
+        // The constructors return borrowed builders (`FooBuilder<'a, ...>`)
+        // when borrowed mode is on, but `Self` here (the plain object type)
+        // never mentions `'a`, so it can't be bound at the `impl` level
+        // (E0207, "lifetime parameter not constrained by the self type");
+        // `write_builder_methods` instead declares it per-method on each
+        // constructor that actually returns a borrowed builder.
         f.write_str("impl ")?;
         f.write_str(&self.inner.name)?;
         f.write_str(" {")?;
         self.write_builder_methods(f)?;
-        f.write_str("\n}\n")
+        f.write_str("\n}\n")?;
+
+        // Each builder gets its own inherent `impl` carrying the setters.
+        for builder in &self.builders {
+            builder.write_builder_impl(f)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -495,11 +1339,20 @@ impl<'a> Display for ApiObjectBuilder<'a> {
         if needs_container {
             container.push_str("#[derive(Debug, Default, Clone)]\nstruct ");
             self.write_container_name(&mut container)?;
+            // The container holds the borrowed params directly (`Option<&'a
+            // str>`, ...), so it must carry the same `'a` the builder does —
+            // otherwise `'a` is used but undeclared (E0261/E0106).
+            if self.uses_lifetime() {
+                container.push_str("<'a>");
+            }
             container.push_str(" {");
             self.write_body_field_if_required(&mut container)?;
 
             f.write_str("\n    inner: ")?;
             self.write_container_name(f)?;
+            if self.uses_lifetime() {
+                f.write_str("<'a>")?;
+            }
             f.write_str(",")?;
         } else {
             self.write_body_field_if_required(f)?;
@@ -548,13 +1401,38 @@ impl<'a> Display for ApiObjectBuilder<'a> {
 
 impl Display for ApiObject {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str("#[derive(Debug, Default, Clone, Deserialize, Serialize)]")?;
-        f.write_str("\npub struct ")?;
+        // If any field carries a schema `default`, we hand-write `Default`
+        // below so the declared defaults are honored; otherwise we derive it.
+        let has_defaults = self.fields.iter().any(|field| field.default.is_some());
+        if has_defaults {
+            f.write_str("#[derive(Debug, Clone, Deserialize, Serialize)]")?;
+        } else {
+            f.write_str("#[derive(Debug, Default, Clone, Deserialize, Serialize)]")?;
+        }
+        if self.deprecated {
+            f.write_str("\n")?;
+            write_deprecated_attr(self.deprecation_note.as_deref(), "", f)?;
+            // `write_deprecated_attr` already trails a newline; trim the leading
+            // one we add below for the struct keyword.
+            f.write_str("pub struct ")?;
+        } else {
+            f.write_str("\npub struct ")?;
+        }
         f.write_str(&self.name)?;
         f.write_str(" {")?;
 
         self.fields.iter().try_for_each(|field| {
             f.write_str("\n    ")?;
+            if field.deprecated {
+                match field.deprecation_note.as_ref() {
+                    Some(note) => write!(
+                        f,
+                        "#[deprecated(note = \"{}\")]\n    ",
+                        escape_str_literal(note)
+                    )?,
+                    None => f.write_str("#[deprecated]\n    ")?,
+                }
+            }
             if let Some(name) = field.rename.as_ref() {
                 f.write_str("#[serde(rename = \"")?;
                 f.write_str(name)?;
@@ -590,7 +1468,44 @@ impl Display for ApiObject {
             f.write_str("\n")?;
         }
 
-        f.write_str("}\n")
+        f.write_str("}\n")?;
+
+        if has_defaults {
+            f.write_str("\nimpl Default for ")?;
+            f.write_str(&self.name)?;
+            f.write_str(" {\n    fn default() -> Self {\n        ")?;
+            f.write_str(&self.name)?;
+            f.write_str(" {")?;
+            self.fields.iter().try_for_each(|field| {
+                f.write_str("\n            ")?;
+                f.write_str(&field.name)?;
+                f.write_str(": ")?;
+                match field.default.as_ref() {
+                    Some(expr) => {
+                        if !field.is_required {
+                            f.write_str("Some(")?;
+                        }
+                        if field.boxed {
+                            f.write_str("Box::new(")?;
+                        }
+                        f.write_str(expr)?;
+                        if field.boxed {
+                            f.write_str(")")?;
+                        }
+                        if !field.is_required {
+                            f.write_str(")")?;
+                        }
+                    }
+                    None => f.write_str("Default::default()")?,
+                }
+
+                f.write_str(",")?;
+                Ok(())
+            })?;
+            f.write_str("\n        }\n    }\n}\n")?;
+        }
+
+        Ok(())
     }
 }
 